@@ -1,7 +1,11 @@
 use anyhow::Result;
-use std::{sync::mpsc, thread};
+use concurrency::pipeline::Pipeline;
+use std::thread;
+use std::time::Duration;
 
 const NUM_PRODUCERS: usize = 4;
+const CHANNEL_CAPACITY: usize = 16;
+const RUN_FOR: Duration = Duration::from_secs(5);
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -10,37 +14,24 @@ struct Msg {
     value: usize,
 }
 
-fn main() -> Result<()> {
-    let (tx, rx) = mpsc::channel();
-
-    for i in 0..NUM_PRODUCERS {
-        let tx = tx.clone();
-        thread::spawn(move || producer(i, tx));
-    }
-
-    let consumer = thread::spawn(move || {
-        for msg in rx {
-            println!("Consumer: {:?}", msg);
-        }
-    });
-
-    consumer
-        .join()
-        .map_err(|e| anyhow::anyhow!("Error joining consumer thread: {:?}", e))?;
-
-    Ok(())
-}
-
-fn producer(idx: usize, tx: mpsc::Sender<Msg>) -> Result<()> {
-    loop {
-        let value = rand::random::<usize>();
-        tx.send(Msg::new(idx, value))?;
-        thread::sleep(std::time::Duration::from_millis(1000));
-    }
-}
-
 impl Msg {
     fn new(idx: usize, value: usize) -> Self {
         Self { idx, value }
     }
 }
+
+fn main() -> Result<()> {
+    let pipeline = Pipeline::spawn(
+        NUM_PRODUCERS,
+        CHANNEL_CAPACITY,
+        |idx| {
+            thread::sleep(Duration::from_millis(1000));
+            Ok(Msg::new(idx, rand::random::<usize>()))
+        },
+        |msg| println!("Consumer: {:?}", msg),
+    );
+
+    thread::sleep(RUN_FOR);
+    pipeline.shutdown();
+    pipeline.join()
+}
@@ -1,15 +1,44 @@
 use core::fmt;
+use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Mul};
+use std::ops::{self, Add, AddAssign, Mul, Sub};
+use std::sync::Mutex;
+use std::thread;
 
 use anyhow::Result;
 
+/// Below this many output elements, `multiply_parallel` falls back to the
+/// serial path rather than pay thread-spawn overhead.
+const PARALLEL_THRESHOLD: usize = 4096;
+
 pub struct Matrix<T> {
     rows: usize,
     cols: usize,
     data: Vec<T>,
 }
 
+/// The multiplicative identity, needed to build identity matrices for
+/// `Matrix::pow` since `T::default()` only gives the additive zero.
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! impl_one {
+    ($($t:ty => $val:expr),* $(,)?) => {
+        $(impl One for $t {
+            fn one() -> Self {
+                $val
+            }
+        })*
+    };
+}
+
+impl_one!(
+    i8 => 1, i16 => 1, i32 => 1, i64 => 1, i128 => 1, isize => 1,
+    u8 => 1, u16 => 1, u32 => 1, u64 => 1, u128 => 1, usize => 1,
+    f32 => 1.0, f64 => 1.0,
+);
+
 pub fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
 where
     T: Debug + Copy + Mul<Output = T> + Add<Output = T> + AddAssign + Default,
@@ -40,6 +69,75 @@ where
     })
 }
 
+/// Same as `multiply`, but spreads the output rows across `num_threads`
+/// workers using a work-stealing queue: each worker drains its own row
+/// queue from the front and, once empty, steals from the back of another
+/// worker's queue so uneven row costs (cache effects, etc.) even out.
+pub fn multiply_parallel<T>(a: &Matrix<T>, b: &Matrix<T>, num_threads: usize) -> Result<Matrix<T>>
+where
+    T: Debug + Copy + Mul<Output = T> + Add<Output = T> + AddAssign + Default + Send + Sync,
+{
+    if a.cols != b.rows {
+        return Err(anyhow::anyhow!(
+            "Cannot multiply matrices with dimensions {}x{} and {}x{}",
+            a.rows,
+            a.cols,
+            b.rows,
+            b.cols
+        ));
+    }
+
+    if num_threads <= 1 || a.rows * b.cols < PARALLEL_THRESHOLD {
+        return multiply(a, b);
+    }
+
+    let num_threads = num_threads.min(a.rows).max(1);
+    let queues: Vec<Mutex<VecDeque<usize>>> = (0..num_threads)
+        .map(|_| Mutex::new(VecDeque::new()))
+        .collect();
+    for row in 0..a.rows {
+        queues[row % num_threads].lock().unwrap().push_back(row);
+    }
+
+    let rows: Mutex<Vec<Option<Vec<T>>>> = Mutex::new(vec![None; a.rows]);
+
+    thread::scope(|scope| {
+        for worker in 0..num_threads {
+            let queues = &queues;
+            let rows = &rows;
+            scope.spawn(move || loop {
+                let row = queues[worker].lock().unwrap().pop_front().or_else(|| {
+                    (0..num_threads)
+                        .filter(|&other| other != worker)
+                        .find_map(|other| queues[other].lock().unwrap().pop_back())
+                });
+                let Some(row) = row else { break };
+
+                let mut row_data = vec![T::default(); b.cols];
+                for (j, cell) in row_data.iter_mut().enumerate() {
+                    for k in 0..a.cols {
+                        *cell += a.data[row * a.cols + k] * b.data[k * b.cols + j];
+                    }
+                }
+                rows.lock().unwrap()[row] = Some(row_data);
+            });
+        }
+    });
+
+    let data = rows
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flat_map(|row| row.expect("every row is assigned to exactly one worker"))
+        .collect();
+
+    Ok(Matrix {
+        rows: a.rows,
+        cols: b.cols,
+        data,
+    })
+}
+
 impl<T: fmt::Debug> Matrix<T> {
     pub fn new(rows: usize, cols: usize, data: impl Into<Vec<T>>) -> Self {
         Self {
@@ -50,6 +148,176 @@ impl<T: fmt::Debug> Matrix<T> {
     }
 }
 
+impl<T: fmt::Debug + Copy + Default + One> Matrix<T> {
+    /// Builds the `n x n` identity matrix: ones on the diagonal, zeros
+    /// elsewhere.
+    pub fn identity(n: usize) -> Self {
+        let mut data = vec![T::default(); n * n];
+        for i in 0..n {
+            data[i * n + i] = T::one();
+        }
+        Self {
+            rows: n,
+            cols: n,
+            data,
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: fmt::Debug + Copy + Mul<Output = T> + Add<Output = T> + AddAssign + Default + One,
+{
+    /// Raises a square matrix to the `exp`-th power by binary
+    /// (square-and-multiply) exponentiation, reusing `multiply`. Runs in
+    /// `O(rows^3 log exp)`, which is the standard trick for evaluating
+    /// linear recurrences via their transition matrix.
+    pub fn pow(&self, exp: u64) -> Result<Matrix<T>> {
+        if self.rows != self.cols {
+            return Err(anyhow::anyhow!(
+                "Cannot raise a non-square {}x{} matrix to a power",
+                self.rows,
+                self.cols
+            ));
+        }
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.clone(),
+        };
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = multiply(&result, &base)?;
+            }
+            base = multiply(&base, &base)?;
+            exp >>= 1;
+        }
+
+        Ok(result)
+    }
+}
+
+impl<T: fmt::Debug + Copy> Matrix<T> {
+    /// Returns the transpose, swapping rows and columns.
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.rows * self.cols);
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self.data[i * self.cols + j]);
+            }
+        }
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+}
+
+impl<T: fmt::Debug + Copy + Add<Output = T>> Matrix<T> {
+    /// Element-wise addition; errors if the dimensions don't match.
+    pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(anyhow::anyhow!(
+                "Cannot add matrices with dimensions {}x{} and {}x{}",
+                self.rows,
+                self.cols,
+                other.rows,
+                other.cols
+            ));
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| a + b)
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        })
+    }
+}
+
+impl<T: fmt::Debug + Copy + Sub<Output = T>> Matrix<T> {
+    /// Element-wise subtraction; errors if the dimensions don't match.
+    pub fn sub(&self, other: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(anyhow::anyhow!(
+                "Cannot subtract matrices with dimensions {}x{} and {}x{}",
+                self.rows,
+                self.cols,
+                other.rows,
+                other.cols
+            ));
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| a - b)
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        })
+    }
+}
+
+impl<T: fmt::Debug + Copy + Mul<Output = T>> Matrix<T> {
+    /// Multiplies every element by the scalar `k`.
+    pub fn scale(&self, k: T) -> Matrix<T> {
+        let data = self.data.iter().map(|&a| a * k).collect();
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+}
+
+impl<T> ops::Mul for &Matrix<T>
+where
+    T: fmt::Debug + Copy + Mul<Output = T> + Add<Output = T> + AddAssign + Default,
+{
+    type Output = Result<Matrix<T>>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        multiply(self, rhs)
+    }
+}
+
+impl<T> ops::Add for &Matrix<T>
+where
+    T: fmt::Debug + Copy + Add<Output = T>,
+{
+    type Output = Result<Matrix<T>>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Matrix::add(self, rhs)
+    }
+}
+
+impl<T> ops::Sub for &Matrix<T>
+where
+    T: fmt::Debug + Copy + Sub<Output = T>,
+{
+    type Output = Result<Matrix<T>>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Matrix::sub(self, rhs)
+    }
+}
+
 impl<T: fmt::Debug> fmt::Display for Matrix<T> {
     // display a 2*3 as {1 2 3, 4 5 6}, 3*2 as {1 2, 3 4, 5 6}
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -110,4 +378,86 @@ mod tests {
         assert_eq!("{22 28}, {49 64}", format!("{}", c));
         Ok(())
     }
+
+    #[test]
+    fn test_matrix_multiply_parallel_matches_serial() -> Result<()> {
+        let rows = 64;
+        let inner = 32;
+        let cols = 64;
+        let a = Matrix::new(rows, inner, vec![2i64; rows * inner]);
+        let b = Matrix::new(inner, cols, vec![3i64; inner * cols]);
+
+        let serial = multiply(&a, &b)?;
+        let parallel = multiply_parallel(&a, &b, 4)?;
+        assert_eq!(format!("{}", serial), format!("{}", parallel));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_multiply_parallel_small_falls_back() -> Result<()> {
+        let a = Matrix::new(2, 3, [1, 2, 3, 4, 5, 6]);
+        let b = Matrix::new(3, 2, [1, 2, 3, 4, 5, 6]);
+        let c = multiply_parallel(&a, &b, 8)?;
+        assert_eq!("{22 28}, {49 64}", format!("{}", c));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_identity() {
+        let i = Matrix::<i32>::identity(3);
+        assert_eq!("{1 0 0}, {0 1 0}, {0 0 1}", format!("{}", i));
+    }
+
+    #[test]
+    fn test_matrix_pow() -> Result<()> {
+        // Fibonacci transition matrix {{1 1} {1 0}}^6 == {{13 8} {8 5}}
+        let fib = Matrix::new(2, 2, [1, 1, 1, 0]);
+        let p = fib.pow(6)?;
+        assert_eq!("{13 8}, {8 5}", format!("{}", p));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_pow_rejects_non_square() {
+        let m = Matrix::new(2, 3, [1, 2, 3, 4, 5, 6]);
+        assert!(m.pow(2).is_err());
+    }
+
+    #[test]
+    fn test_matrix_transpose() {
+        let m = Matrix::new(2, 3, [1, 2, 3, 4, 5, 6]);
+        assert_eq!("{1 4}, {2 5}, {3 6}", format!("{}", m.transpose()));
+    }
+
+    #[test]
+    fn test_matrix_add_and_sub() -> Result<()> {
+        let a = Matrix::new(2, 2, [1, 2, 3, 4]);
+        let b = Matrix::new(2, 2, [4, 3, 2, 1]);
+        assert_eq!("{5 5}, {5 5}", format!("{}", a.add(&b)?));
+        assert_eq!("{-3 -1}, {1 3}", format!("{}", a.sub(&b)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_add_rejects_mismatched_dimensions() {
+        let a = Matrix::new(2, 2, [1, 2, 3, 4]);
+        let b = Matrix::new(2, 3, [1, 2, 3, 4, 5, 6]);
+        assert!(a.add(&b).is_err());
+    }
+
+    #[test]
+    fn test_matrix_scale() {
+        let m = Matrix::new(2, 2, [1, 2, 3, 4]);
+        assert_eq!("{2 4}, {6 8}", format!("{}", m.scale(2)));
+    }
+
+    #[test]
+    fn test_matrix_operator_overloads() -> Result<()> {
+        let a = Matrix::new(2, 2, [1, 2, 3, 4]);
+        let b = Matrix::new(2, 2, [5, 6, 7, 8]);
+        assert_eq!("{19 22}, {43 50}", format!("{}", (&a * &b)?));
+        assert_eq!("{6 8}, {10 12}", format!("{}", (&a + &b)?));
+        assert_eq!("{-4 -4}, {-4 -4}", format!("{}", (&a - &b)?));
+        Ok(())
+    }
 }
@@ -0,0 +1,147 @@
+use core::fmt;
+use std::ops::{Add, AddAssign, Mul};
+
+use crate::matrix::One;
+
+/// An integer modulo the compile-time prime `M`. Implements just enough
+/// (`Copy + Default + Debug + Add + AddAssign + Mul + One`) to drop straight into
+/// `Matrix<T>`, so a `Matrix<ModInt<1_000_000_007>>` can be built and
+/// passed through `multiply`/`pow` unchanged for exact modular counts.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModInt<const M: u64>(u64);
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        Self(value % M)
+    }
+
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Raises `self` to the `e`-th power by binary exponentiation.
+    pub fn pow(self, mut e: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::new(1);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            e >>= 1;
+        }
+        result
+    }
+
+    /// Modular inverse via Fermat's little theorem (`self^(M-2)`); `M`
+    /// must be prime.
+    pub fn inv(self) -> Self {
+        self.pow(M - 2)
+    }
+}
+
+impl<const M: u64> One for ModInt<M> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> Add for ModInt<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(((self.0 as u128 + rhs.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> AddAssign for ModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: u64> Mul for ModInt<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self((self.0 as u128 * rhs.0 as u128 % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> fmt::Display for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const M: u64> fmt::Debug for ModInt<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ModInt<{}>({})", M, self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::Matrix;
+
+    const MOD: u64 = 1_000_000_007;
+
+    #[test]
+    fn test_modint_add_wraps() {
+        let a = ModInt::<MOD>::new(MOD - 1);
+        let b = ModInt::<MOD>::new(2);
+        assert_eq!((a + b).value(), 1);
+    }
+
+    #[test]
+    fn test_modint_add_avoids_overflow() {
+        // M is large enough that `self.0 + rhs.0` would overflow a u64.
+        const HUGE_MOD: u64 = u64::MAX - 1;
+        let a = ModInt::<HUGE_MOD>::new(HUGE_MOD - 2);
+        let b = ModInt::<HUGE_MOD>::new(HUGE_MOD - 2);
+        assert_eq!((a + b).value(), HUGE_MOD - 4);
+    }
+
+    #[test]
+    fn test_modint_mul_avoids_overflow() {
+        let a = ModInt::<MOD>::new(MOD - 1);
+        let b = ModInt::<MOD>::new(MOD - 1);
+        assert_eq!((a * b).value(), 1);
+    }
+
+    #[test]
+    fn test_modint_inv_is_multiplicative_inverse() {
+        let a = ModInt::<MOD>::new(12345);
+        assert_eq!((a * a.inv()).value(), 1);
+    }
+
+    #[test]
+    fn test_modint_display_and_debug() {
+        let a = ModInt::<MOD>::new(42);
+        assert_eq!("42", format!("{}", a));
+        assert_eq!(format!("ModInt<{}>(42)", MOD), format!("{:?}", a));
+    }
+
+    #[test]
+    fn test_matrix_of_modint_pow() -> anyhow::Result<()> {
+        // The Fibonacci transition matrix [[1 1] [1 0]]^n == [[F(n+1) F(n)] [F(n) F(n-1)]],
+        // computed exactly mod a large prime via `Matrix::pow` over `ModInt`.
+        let one = ModInt::<MOD>::new(1);
+        let zero = ModInt::<MOD>::new(0);
+        let fib = Matrix::new(2, 2, [one, one, one, zero]);
+
+        let p = fib.pow(10)?;
+        assert_eq!(
+            format!("{}", p),
+            format!(
+                "{{{:?} {:?}}}, {{{:?} {:?}}}",
+                ModInt::<MOD>::new(89),
+                ModInt::<MOD>::new(55),
+                ModInt::<MOD>::new(55),
+                ModInt::<MOD>::new(34)
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use anyhow::Result;
+
+/// A reusable producer/consumer pipeline: producers feed a consumer over
+/// a bounded channel, so a fast producer blocks instead of growing memory
+/// without limit, and the whole thing shuts down cooperatively instead of
+/// running forever. Producers check a shared stop-flag each iteration; once
+/// set they exit their loop, the consumer drains whatever is left in the
+/// channel, and `join` propagates every thread's `Result`.
+pub struct Pipeline {
+    stop: Arc<AtomicBool>,
+    producers: Vec<JoinHandle<Result<()>>>,
+    consumer: Option<JoinHandle<Result<()>>>,
+}
+
+impl Pipeline {
+    /// Spawns `num_producers` workers (each calling `produce(idx)` in a
+    /// loop) feeding a single consumer over a channel bounded to
+    /// `capacity` in-flight messages.
+    pub fn spawn<T, P, C>(num_producers: usize, capacity: usize, produce: P, mut consume: C) -> Self
+    where
+        T: Send + 'static,
+        P: Fn(usize) -> Result<T> + Send + Sync + 'static,
+        C: FnMut(T) + Send + 'static,
+    {
+        let (tx, rx): (SyncSender<T>, Receiver<T>) = mpsc::sync_channel(capacity);
+        let stop = Arc::new(AtomicBool::new(false));
+        let produce = Arc::new(produce);
+
+        let producers = (0..num_producers)
+            .map(|idx| {
+                let tx = tx.clone();
+                let stop = Arc::clone(&stop);
+                let produce = Arc::clone(&produce);
+                thread::spawn(move || -> Result<()> {
+                    while !stop.load(Ordering::Relaxed) {
+                        let msg = produce(idx)?;
+                        if tx.send(msg).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                })
+            })
+            .collect();
+        drop(tx);
+
+        let consumer = thread::spawn(move || -> Result<()> {
+            for msg in rx {
+                consume(msg);
+            }
+            Ok(())
+        });
+
+        Self {
+            stop,
+            producers,
+            consumer: Some(consumer),
+        }
+    }
+
+    /// Signals every producer to stop after its current iteration.
+    pub fn shutdown(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+
+    /// Joins every producer and the consumer, propagating the first error
+    /// encountered (either a panic or a `Result::Err` returned by a
+    /// thread).
+    pub fn join(mut self) -> Result<()> {
+        let mut first_err = None;
+        for handle in self.producers.drain(..) {
+            let res = handle
+                .join()
+                .map_err(|e| anyhow::anyhow!("Error joining producer thread: {:?}", e))
+                .and_then(|r| r);
+            if let Err(e) = res {
+                first_err.get_or_insert(e);
+            }
+        }
+
+        let res = self
+            .consumer
+            .take()
+            .expect("Pipeline::join called more than once")
+            .join()
+            .map_err(|e| anyhow::anyhow!("Error joining consumer thread: {:?}", e))
+            .and_then(|r| r);
+        if let Err(e) = res {
+            first_err.get_or_insert(e);
+        }
+
+        first_err.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    #[test]
+    fn test_pipeline_shutdown_accounts_for_every_message() {
+        let produced = Arc::new(AtomicUsize::new(0));
+        let consumed = Arc::new(AtomicUsize::new(0));
+
+        let produce_count = Arc::clone(&produced);
+        let consume_count = Arc::clone(&consumed);
+
+        let pipeline = Pipeline::spawn(
+            4,
+            8,
+            move |_idx| {
+                produce_count.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            },
+            move |_: ()| {
+                consume_count.fetch_add(1, Ordering::Relaxed);
+            },
+        );
+
+        thread::sleep(Duration::from_millis(50));
+        pipeline.shutdown();
+        pipeline.join().unwrap();
+
+        let produced = produced.load(Ordering::Relaxed);
+        assert!(produced > 0);
+        assert_eq!(produced, consumed.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_pipeline_propagates_producer_error() {
+        let pipeline = Pipeline::spawn(1, 4, |_idx| Err::<(), _>(anyhow::anyhow!("boom")), |_: ()| {});
+
+        assert!(pipeline.join().is_err());
+    }
+}